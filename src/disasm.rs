@@ -0,0 +1,392 @@
+//! Text assembler/disassembler for `Inst`, gated behind the `disasm`
+//! feature so the core interpreter doesn't pay for string handling it
+//! doesn't need.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{Inst, Reg};
+
+impl Reg {
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Reg::R0 => "r0",
+            Reg::R1 => "r1",
+            Reg::R2 => "r2",
+            Reg::R3 => "r3",
+            Reg::R4 => "r4",
+            Reg::R5 => "r5",
+            Reg::R6 => "r6",
+            Reg::R7 => "r7",
+            Reg::PC => "pc",
+            Reg::ZF => "zf",
+            Reg::LR => "lr",
+            Reg::Me => "me",
+            // Not an addressable register — a sentinel for the register
+            // count — so no instruction should ever carry it as an operand.
+            Reg::RegCount => unreachable!("RegCount is not an addressable register"),
+        }
+    }
+
+    pub(crate) fn parse_name(s: &str) -> Option<Reg> {
+        match s.to_lowercase().as_str() {
+            "r0" => Some(Reg::R0),
+            "r1" => Some(Reg::R1),
+            "r2" => Some(Reg::R2),
+            "r3" => Some(Reg::R3),
+            "r4" => Some(Reg::R4),
+            "r5" => Some(Reg::R5),
+            "r6" => Some(Reg::R6),
+            "r7" => Some(Reg::R7),
+            "pc" => Some(Reg::PC),
+            "zf" => Some(Reg::ZF),
+            "lr" => Some(Reg::LR),
+            "me" => Some(Reg::Me),
+            // "regcount" is deliberately not accepted: RegCount is a
+            // sentinel one past the last real register, not something a
+            // program should ever be able to name (it indexes out of
+            // bounds into Register::registers if it reaches Register::set).
+            _ => None,
+        }
+    }
+}
+
+/// A parse error from [`assemble`], pointing at the 1-indexed source line.
+#[derive(Debug)]
+pub(crate) struct AsmError {
+    pub(crate) line: usize,
+    pub(crate) message: String,
+}
+
+impl fmt::Display for AsmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for AsmError {}
+
+fn err(line: usize, message: impl Into<String>) -> AsmError {
+    AsmError {
+        line,
+        message: message.into(),
+    }
+}
+
+fn quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn unquote(tok: &str, line: usize) -> Result<String, AsmError> {
+    let inner = tok
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| err(line, format!("expected a quoted string, got '{}'", tok)))?;
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('"') => out.push('"'),
+                Some('\\') => out.push('\\'),
+                Some(other) => out.push(other),
+                None => return Err(err(line, "dangling escape at end of string")),
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    Ok(out)
+}
+
+/// Renders a single instruction as one line of text, e.g. `add r0, r1, r2`.
+pub(crate) fn disassemble_inst(inst: &Inst) -> String {
+    match inst {
+        Inst::Int(r, v) => format!("int {}, {}", r.name(), v),
+        Inst::Float(r, v) => format!("float {}, {}", r.name(), v),
+        Inst::Bool(r, v) => format!("bool {}, {}", r.name(), v),
+        Inst::Ref(r, v) => format!("ref {}, {}", r.name(), v),
+        Inst::String(r, s) => format!("string {}, {}", r.name(), quote(s)),
+        Inst::Atom(r, s) => format!("atom {}, {}", r.name(), s),
+        Inst::List(r, n) => format!("list {}, {}", r.name(), n),
+        Inst::Tuple(r, n) => format!("tuple {}, {}", r.name(), n),
+        Inst::Map(r) => format!("map {}", r.name()),
+        Inst::SetC(t, k, v) => format!("setc {}, {}, {}", t.name(), k.name(), v.name()),
+        Inst::MoveC(f, k, t) => format!("movec {}, {}, {}", f.name(), k.name(), t.name()),
+        Inst::Move(a, b) => format!("move {}, {}", a.name(), b.name()),
+        Inst::Store(r, a) => format!("store {}, {}", r.name(), a),
+        Inst::Load(a, r) => format!("load {}, {}", a, r.name()),
+        Inst::Send(a, b) => format!("send {}, {}", a.name(), b.name()),
+        Inst::Recv(r) => format!("recv {}", r.name()),
+        Inst::Add(a, b, c) => format!("add {}, {}, {}", a.name(), b.name(), c.name()),
+        Inst::Sub(a, b, c) => format!("sub {}, {}, {}", a.name(), b.name(), c.name()),
+        Inst::Mul(a, b, c) => format!("mul {}, {}, {}", a.name(), b.name(), c.name()),
+        Inst::Div(a, b, c) => format!("div {}, {}, {}", a.name(), b.name(), c.name()),
+        Inst::Mod(a, b, c) => format!("mod {}, {}, {}", a.name(), b.name(), c.name()),
+        Inst::Jump(a) => format!("jump {}", a),
+        Inst::JumpIf(a) => format!("jumpif {}", a),
+        Inst::Eq(a, b) => format!("eq {}, {}", a.name(), b.name()),
+        Inst::Ne(a, b) => format!("ne {}, {}", a.name(), b.name()),
+        Inst::Gt(a, b) => format!("gt {}, {}", a.name(), b.name()),
+        Inst::Lt(a, b) => format!("lt {}, {}", a.name(), b.name()),
+        Inst::Gte(a, b) => format!("gte {}, {}", a.name(), b.name()),
+        Inst::Lte(a, b) => format!("lte {}, {}", a.name(), b.name()),
+        Inst::Push(r) => format!("push {}", r.name()),
+        Inst::Pop(r) => format!("pop {}", r.name()),
+        Inst::Trap(a) => format!("trap {}", a),
+        Inst::Alloc(r) => format!("alloc {}", r.name()),
+        Inst::Call(func_id, argc) => format!("call {}, {}", func_id, argc),
+        Inst::Hlt => "hlt".to_string(),
+    }
+}
+
+/// Renders a whole program, one instruction per line.
+pub(crate) fn disassemble(program: &[Inst]) -> String {
+    program
+        .iter()
+        .map(disassemble_inst)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn strip_comment(line: &str) -> &str {
+    match line.find(';') {
+        Some(i) => &line[..i],
+        None => line,
+    }
+}
+
+fn split_operands(rest: &str) -> Vec<&str> {
+    if rest.is_empty() {
+        return Vec::new();
+    }
+    let mut parts = Vec::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                parts.push(rest[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(rest[start..].trim());
+    parts
+}
+
+fn resolve_addr(tok: &str, labels: &HashMap<String, usize>, line: usize) -> Result<usize, AsmError> {
+    if let Ok(n) = tok.parse::<usize>() {
+        return Ok(n);
+    }
+    labels
+        .get(tok)
+        .copied()
+        .ok_or_else(|| err(line, format!("unknown label '{}'", tok)))
+}
+
+fn operand<'a>(operands: &[&'a str], idx: usize, mnemonic: &str, line: usize) -> Result<&'a str, AsmError> {
+    operands
+        .get(idx)
+        .copied()
+        .ok_or_else(|| err(line, format!("'{}' expects an operand at position {}", mnemonic, idx + 1)))
+}
+
+fn reg_operand(operands: &[&str], idx: usize, mnemonic: &str, line: usize) -> Result<Reg, AsmError> {
+    let tok = operand(operands, idx, mnemonic, line)?;
+    Reg::parse_name(tok).ok_or_else(|| err(line, format!("unknown register '{}'", tok)))
+}
+
+fn addr_operand(
+    operands: &[&str],
+    idx: usize,
+    mnemonic: &str,
+    labels: &HashMap<String, usize>,
+    line: usize,
+) -> Result<usize, AsmError> {
+    let tok = operand(operands, idx, mnemonic, line)?;
+    resolve_addr(tok, labels, line)
+}
+
+fn parse_inst(line_text: &str, labels: &HashMap<String, usize>, line: usize) -> Result<Inst, AsmError> {
+    let mut split = line_text.splitn(2, char::is_whitespace);
+    let mnemonic = split.next().unwrap_or("").to_lowercase();
+    let rest = split.next().unwrap_or("").trim();
+    let operands = split_operands(rest);
+
+    match mnemonic.as_str() {
+        "int" => Ok(Inst::Int(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            operand(&operands, 1, &mnemonic, line)?
+                .parse()
+                .map_err(|_| err(line, format!("invalid integer '{}'", operands[1])))?,
+        )),
+        "float" => Ok(Inst::Float(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            operand(&operands, 1, &mnemonic, line)?
+                .parse()
+                .map_err(|_| err(line, format!("invalid float '{}'", operands[1])))?,
+        )),
+        "bool" => Ok(Inst::Bool(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            operand(&operands, 1, &mnemonic, line)?
+                .parse()
+                .map_err(|_| err(line, format!("invalid bool '{}'", operands[1])))?,
+        )),
+        "ref" => Ok(Inst::Ref(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            addr_operand(&operands, 1, &mnemonic, labels, line)?,
+        )),
+        "string" => Ok(Inst::String(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            unquote(operand(&operands, 1, &mnemonic, line)?, line)?,
+        )),
+        "atom" => Ok(Inst::Atom(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            operand(&operands, 1, &mnemonic, line)?.to_string(),
+        )),
+        "list" => Ok(Inst::List(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            addr_operand(&operands, 1, &mnemonic, labels, line)?,
+        )),
+        "tuple" => Ok(Inst::Tuple(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            addr_operand(&operands, 1, &mnemonic, labels, line)?,
+        )),
+        "map" => Ok(Inst::Map(reg_operand(&operands, 0, &mnemonic, line)?)),
+        "setc" => Ok(Inst::SetC(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+            reg_operand(&operands, 2, &mnemonic, line)?,
+        )),
+        "movec" => Ok(Inst::MoveC(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+            reg_operand(&operands, 2, &mnemonic, line)?,
+        )),
+        "move" => Ok(Inst::Move(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+        )),
+        "store" => Ok(Inst::Store(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            addr_operand(&operands, 1, &mnemonic, labels, line)?,
+        )),
+        "load" => Ok(Inst::Load(
+            addr_operand(&operands, 0, &mnemonic, labels, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+        )),
+        "send" => Ok(Inst::Send(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+        )),
+        "recv" => Ok(Inst::Recv(reg_operand(&operands, 0, &mnemonic, line)?)),
+        "add" => Ok(Inst::Add(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+            reg_operand(&operands, 2, &mnemonic, line)?,
+        )),
+        "sub" => Ok(Inst::Sub(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+            reg_operand(&operands, 2, &mnemonic, line)?,
+        )),
+        "mul" => Ok(Inst::Mul(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+            reg_operand(&operands, 2, &mnemonic, line)?,
+        )),
+        "div" => Ok(Inst::Div(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+            reg_operand(&operands, 2, &mnemonic, line)?,
+        )),
+        "mod" => Ok(Inst::Mod(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+            reg_operand(&operands, 2, &mnemonic, line)?,
+        )),
+        "jump" => Ok(Inst::Jump(addr_operand(&operands, 0, &mnemonic, labels, line)?)),
+        "jumpif" => Ok(Inst::JumpIf(addr_operand(&operands, 0, &mnemonic, labels, line)?)),
+        "eq" => Ok(Inst::Eq(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+        )),
+        "ne" => Ok(Inst::Ne(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+        )),
+        "gt" => Ok(Inst::Gt(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+        )),
+        "lt" => Ok(Inst::Lt(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+        )),
+        "gte" => Ok(Inst::Gte(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+        )),
+        "lte" => Ok(Inst::Lte(
+            reg_operand(&operands, 0, &mnemonic, line)?,
+            reg_operand(&operands, 1, &mnemonic, line)?,
+        )),
+        "push" => Ok(Inst::Push(reg_operand(&operands, 0, &mnemonic, line)?)),
+        "pop" => Ok(Inst::Pop(reg_operand(&operands, 0, &mnemonic, line)?)),
+        "trap" => Ok(Inst::Trap(addr_operand(&operands, 0, &mnemonic, labels, line)?)),
+        "alloc" => Ok(Inst::Alloc(reg_operand(&operands, 0, &mnemonic, line)?)),
+        "call" => Ok(Inst::Call(
+            addr_operand(&operands, 0, &mnemonic, labels, line)?,
+            addr_operand(&operands, 1, &mnemonic, labels, line)?,
+        )),
+        "hlt" => Ok(Inst::Hlt),
+        other => Err(err(line, format!("unknown mnemonic '{}'", other))),
+    }
+}
+
+/// Parses assembly text into a program. Labels are lines of the form
+/// `name:` naming the address of the next instruction; `Jump`, `JumpIf`
+/// and `Ref` operands may reference a label instead of a raw address.
+/// Resolution is a two-pass scan: the first pass records label addresses,
+/// the second parses every instruction line.
+pub(crate) fn assemble(source: &str) -> Result<Vec<Inst>, AsmError> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut labels: HashMap<String, usize> = HashMap::new();
+    let mut addr = 0usize;
+    for raw in &lines {
+        let line = strip_comment(raw).trim();
+        if line.is_empty() {
+            continue;
+        }
+        match line.strip_suffix(':') {
+            Some(label) => {
+                labels.insert(label.trim().to_string(), addr);
+            }
+            None => addr += 1,
+        }
+    }
+
+    let mut program = Vec::new();
+    for (i, raw) in lines.iter().enumerate() {
+        let line_no = i + 1;
+        let line = strip_comment(raw).trim();
+        if line.is_empty() || line.ends_with(':') {
+            continue;
+        }
+        program.push(parse_inst(line, &labels, line_no)?);
+    }
+    Ok(program)
+}
@@ -1,11 +1,31 @@
+use std::collections::HashMap;
 use std::hash::{DefaultHasher, Hash, Hasher};
-use std::io;
 
-#[derive(Debug)]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "disasm")]
+mod disasm;
+
+/// Serializes an `f64` by its bit pattern rather than its textual value,
+/// so a snapshot round-trips exactly (matching how `Hash` already treats
+/// floats) instead of drifting through a decimal re-parse.
+mod float_bits {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        value.to_bits().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        Ok(f64::from_bits(u64::deserialize(deserializer)?))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 enum Value {
     Ref(usize),
     Int(i64),
-    Float(f64),
+    Float(#[serde(with = "float_bits")] f64),
     Bool(bool),
     String(String),
     Atom(String),
@@ -14,12 +34,27 @@ enum Value {
     Map(std::collections::HashMap<Value, Value>),
 }
 
+// Int and Float share one numeric hash space (an integral float hashes the
+// same as the equal Int) so that `Ord`'s cross-type numeric comparison
+// below and this `Hash` agree on which values are equal, as required by
+// the `HashMap` key invariant.
+fn hash_numeric<H: Hasher>(f: f64, state: &mut H) {
+    if f.is_finite() && f.fract() == 0.0 && f >= i64::MIN as f64 && f <= i64::MAX as f64 {
+        (f as i64).hash(state);
+    } else if f.is_nan() {
+        // Every NaN compares equal under `cmp_f64`, so all payloads hash alike.
+        f64::NAN.to_bits().hash(state);
+    } else {
+        f.to_bits().hash(state);
+    }
+}
+
 impl Hash for Value {
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self {
             Value::Ref(r) => r.hash(state),
-            Value::Int(i) => i.hash(state),
-            Value::Float(f) => f.to_bits().hash(state),
+            Value::Int(i) => hash_numeric(*i as f64, state),
+            Value::Float(f) => hash_numeric(*f, state),
             Value::String(s) => s.hash(state),
             Value::Atom(a) => a.hash(state),
             Value::Bool(b) => b.hash(state),
@@ -34,15 +69,113 @@ impl Hash for Value {
                 }
             }
             Value::Map(m) => {
+                // Order-independent: combine each entry's own hash so
+                // that two maps with the same entries in different
+                // insertion order still hash equal.
+                let mut combined: u64 = 0;
                 for (key, value) in m {
-                    key.hash(state);
-                    value.hash(state);
+                    let mut entry_hasher = DefaultHasher::new();
+                    key.hash(&mut entry_hasher);
+                    value.hash(&mut entry_hasher);
+                    combined ^= entry_hasher.finish();
                 }
+                combined.hash(state);
             }
         }
     }
 }
 
+fn type_rank(value: &Value) -> u8 {
+    match value {
+        Value::Ref(_) => 0,
+        Value::Bool(_) => 1,
+        Value::Int(_) | Value::Float(_) => 2,
+        Value::Atom(_) => 3,
+        Value::String(_) => 4,
+        Value::List(_) => 5,
+        Value::Tuple(_) => 6,
+        Value::Map(_) => 7,
+    }
+}
+
+// Total order over `f64`, unlike `partial_cmp`: NaN sorts as greater than
+// every other float (and equal to itself), so `Ord` never needs to bail.
+fn cmp_f64(a: f64, b: f64) -> std::cmp::Ordering {
+    a.partial_cmp(&b).unwrap_or_else(|| match (a.is_nan(), b.is_nan()) {
+        (true, true) => std::cmp::Ordering::Equal,
+        (true, false) => std::cmp::Ordering::Greater,
+        (false, true) => std::cmp::Ordering::Less,
+        (false, false) => std::cmp::Ordering::Equal,
+    })
+}
+
+// Exact `Int` vs `Float` comparison; `a as f64` would round large ints and
+// make equality non-transitive. NaN sorts above every int, as in `cmp_f64`.
+fn cmp_int_f64(a: i64, b: f64) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+    if b.is_nan() || b >= 9_223_372_036_854_775_808.0 {
+        return Ordering::Less;
+    }
+    if b < -9_223_372_036_854_775_808.0 {
+        return Ordering::Greater;
+    }
+    let t = b.trunc();
+    a.cmp(&(t as i64)).then(cmp_f64(t, b))
+}
+
+fn cmp_maps(
+    a: &std::collections::HashMap<Value, Value>,
+    b: &std::collections::HashMap<Value, Value>,
+) -> std::cmp::Ordering {
+    let mut a_entries: Vec<(&Value, &Value)> = a.iter().collect();
+    let mut b_entries: Vec<(&Value, &Value)> = b.iter().collect();
+    a_entries.sort_by(|x, y| x.0.cmp(y.0));
+    b_entries.sort_by(|x, y| x.0.cmp(y.0));
+    a_entries.cmp(&b_entries)
+}
+
+/// A single total order over every `Value`, used both for the comparison
+/// opcodes and (via `PartialEq`/`Eq` below) for `HashMap` key equality.
+/// Values of a fixed type rank (see `type_rank`) compare lower than any
+/// value of a higher-ranked type, except `Int`/`Float` which share a rank
+/// and compare numerically against each other.
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Value::Ref(a), Value::Ref(b)) => a.cmp(b),
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            (Value::Int(a), Value::Int(b)) => a.cmp(b),
+            (Value::Float(a), Value::Float(b)) => cmp_f64(*a, *b),
+            (Value::Int(a), Value::Float(b)) => cmp_int_f64(*a, *b),
+            (Value::Float(a), Value::Int(b)) => cmp_int_f64(*b, *a).reverse(),
+            (Value::Atom(a), Value::Atom(b)) => a.cmp(b),
+            (Value::String(a), Value::String(b)) => a.cmp(b),
+            (Value::List(a), Value::List(b)) => a.cmp(b),
+            (Value::Tuple(a), Value::Tuple(b)) => a.cmp(b),
+            (Value::Map(a), Value::Map(b)) => cmp_maps(a, b),
+            _ => type_rank(self).cmp(&type_rank(other)),
+        }
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// `Value` is used as a `HashMap` key, which requires `Eq` to agree with
+// `Hash`. Routing equality through the same total order that `Ord` uses
+// is what lets `Int(1) == Float(1.0)` hash the same way too, closing the
+// gap a naive per-variant `Eq` would leave.
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for Value {}
+
 impl Value {
     fn clone(&self) -> Value {
         match self {
@@ -67,16 +200,22 @@ impl Value {
                 Value::Tuple(new_tuple)
             }
             Value::Map(m) => {
-                let new_map: std::collections::HashMap<Value, Value> =
-                    std::collections::HashMap::new();
+                let mut new_map: std::collections::HashMap<Value, Value> =
+                    std::collections::HashMap::with_capacity(m.len());
+                for (key, value) in m {
+                    new_map.insert(key.clone(), value.clone());
+                }
                 Value::Map(new_map)
             }
         }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Reg {
+// RegCount is a sentinel for the register count, not a real register; it's
+// fine that its name echoes the enum's.
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub(crate) enum Reg {
     R0,
     R1,
     R2,
@@ -88,10 +227,12 @@ enum Reg {
     PC,
     ZF,
     LR,
+    Me,
     RegCount,
 }
 
-enum Inst {
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) enum Inst {
     Int(Reg, i64),
     Float(Reg, f64),
     Bool(Reg, bool),
@@ -123,9 +264,29 @@ enum Inst {
     Lte(Reg, Reg),
     Push(Reg),
     Pop(Reg),
+    Trap(usize),
+    Alloc(Reg),
+    Call(usize, usize),
     Hlt,
 }
 
+/// A recoverable error raised while executing a single instruction. Faults
+/// never unwind the process; they halt the offending actor (or, if it has
+/// installed a handler via `Trap`, divert it there) so the rest of the
+/// system keeps running.
+#[derive(Debug, Clone)]
+enum Fault {
+    DivByZero,
+    StackUnderflow,
+    BadIndex,
+    // Read via `Debug` when a fault is logged or inspected, not by field
+    // access, so the compiler can't see the real usage.
+    #[allow(dead_code)]
+    TypeMismatch { inst: &'static str, got: String },
+    BadAddress,
+    HeapOverflow,
+}
+
 struct Mailbox {
     messages: Vec<Value>,
     lock: std::sync::Mutex<()>,
@@ -155,7 +316,7 @@ impl Mailbox {
 }
 
 struct Register {
-    registers: [Value; 11],
+    registers: [Value; 12],
 }
 
 impl Register {
@@ -166,6 +327,7 @@ impl Register {
         println!("PC: {:?}", self.registers[8]);
         println!("ZF: {:?}", self.registers[9]);
         println!("LR: {:?}", self.registers[10]);
+        println!("Me: {:?}", self.registers[11]);
     }
     fn get(&self, reg: Reg) -> Value {
         self.registers[reg as usize].clone()
@@ -175,7 +337,7 @@ impl Register {
         self.registers[reg as usize] = value.clone()
     }
 
-    fn new() -> Register {
+    fn new(pid: usize) -> Register {
         Register {
             registers: [
                 Value::Ref(0),
@@ -189,22 +351,233 @@ impl Register {
                 Value::Ref(0),
                 Value::Bool(false),
                 Value::Ref(0),
+                Value::Ref(pid),
             ],
         }
     }
 }
 
+/// A host function callable via `Inst::Call`. Arguments arrive already
+/// popped off the VM's stack; the return value is left in `R0` by `tick`.
+type NativeFn = fn(&mut ActorVm, &[Value]) -> Result<Value, Fault>;
+
+const NATIVE_STR_CONCAT: usize = 0;
+const NATIVE_STR_LEN: usize = 1;
+const NATIVE_LIST_PUSH: usize = 2;
+const NATIVE_LIST_LEN: usize = 3;
+const NATIVE_LIST_MAP: usize = 4;
+const NATIVE_MAP_GET: usize = 5;
+const NATIVE_MAP_INSERT: usize = 6;
+const NATIVE_INT_TO_FLOAT: usize = 7;
+const NATIVE_FLOAT_TO_INT: usize = 8;
+const NATIVE_PRINT: usize = 9;
+
+fn clone_values(items: &[Value]) -> Vec<Value> {
+    items.iter().map(|v| v.clone()).collect()
+}
+
+fn native_type_mismatch(inst: &'static str, args: &[Value]) -> Fault {
+    Fault::TypeMismatch {
+        inst,
+        got: format!("{:?}", args),
+    }
+}
+
+fn native_str_concat(_vm: &mut ActorVm, args: &[Value]) -> Result<Value, Fault> {
+    match args {
+        [Value::String(a), Value::String(b)] => Ok(Value::String(format!("{}{}", a, b))),
+        _ => Err(native_type_mismatch("call:str_concat", args)),
+    }
+}
+
+fn native_str_len(_vm: &mut ActorVm, args: &[Value]) -> Result<Value, Fault> {
+    match args {
+        [Value::String(s)] => Ok(Value::Int(s.len() as i64)),
+        _ => Err(native_type_mismatch("call:str_len", args)),
+    }
+}
+
+fn native_list_push(_vm: &mut ActorVm, args: &[Value]) -> Result<Value, Fault> {
+    match args {
+        [Value::List(list), item] => {
+            let mut new_list = clone_values(list);
+            new_list.push(item.clone());
+            Ok(Value::List(new_list))
+        }
+        _ => Err(native_type_mismatch("call:list_push", args)),
+    }
+}
+
+fn native_list_len(_vm: &mut ActorVm, args: &[Value]) -> Result<Value, Fault> {
+    match args {
+        [Value::List(list)] => Ok(Value::Int(list.len() as i64)),
+        _ => Err(native_type_mismatch("call:list_len", args)),
+    }
+}
+
+/// Applies another native, looked up by its id in the same registry, to
+/// every element of a list. `func_id` is passed as a `Value::Int` since
+/// natives only take `Value` arguments.
+fn native_list_map(vm: &mut ActorVm, args: &[Value]) -> Result<Value, Fault> {
+    match args {
+        [Value::List(list), Value::Int(func_id)] => {
+            let func: NativeFn = *vm
+                .natives
+                .get(*func_id as usize)
+                .ok_or_else(|| native_type_mismatch("call:list_map", args))?;
+            let mut mapped = Vec::with_capacity(list.len());
+            for item in list {
+                mapped.push(func(vm, std::slice::from_ref(item))?);
+            }
+            Ok(Value::List(mapped))
+        }
+        _ => Err(native_type_mismatch("call:list_map", args)),
+    }
+}
+
+fn native_map_get(_vm: &mut ActorVm, args: &[Value]) -> Result<Value, Fault> {
+    match args {
+        [Value::Map(map), key] => Ok(map.get(key).map(|v| v.clone()).unwrap_or(Value::Ref(NULL_ADDR))),
+        _ => Err(native_type_mismatch("call:map_get", args)),
+    }
+}
+
+fn native_map_insert(_vm: &mut ActorVm, args: &[Value]) -> Result<Value, Fault> {
+    match args {
+        [Value::Map(map), key, value] => {
+            let mut new_map: std::collections::HashMap<Value, Value> =
+                std::collections::HashMap::with_capacity(map.len() + 1);
+            for (k, v) in map.iter() {
+                new_map.insert(k.clone(), v.clone());
+            }
+            new_map.insert(key.clone(), value.clone());
+            Ok(Value::Map(new_map))
+        }
+        _ => Err(native_type_mismatch("call:map_insert", args)),
+    }
+}
+
+fn native_int_to_float(_vm: &mut ActorVm, args: &[Value]) -> Result<Value, Fault> {
+    match args {
+        [Value::Int(i)] => Ok(Value::Float(*i as f64)),
+        _ => Err(native_type_mismatch("call:int_to_float", args)),
+    }
+}
+
+fn native_float_to_int(_vm: &mut ActorVm, args: &[Value]) -> Result<Value, Fault> {
+    match args {
+        [Value::Float(f)] => Ok(Value::Int(*f as i64)),
+        _ => Err(native_type_mismatch("call:float_to_int", args)),
+    }
+}
+
+fn native_print(_vm: &mut ActorVm, args: &[Value]) -> Result<Value, Fault> {
+    match args {
+        [value] => {
+            println!("{:?}", value);
+            Ok(Value::Ref(NULL_ADDR))
+        }
+        _ => Err(native_type_mismatch("call:print", args)),
+    }
+}
+
+fn native_table() -> Vec<NativeFn> {
+    vec![
+        native_str_concat,
+        native_str_len,
+        native_list_push,
+        native_list_len,
+        native_list_map,
+        native_map_get,
+        native_map_insert,
+        native_int_to_float,
+        native_float_to_int,
+        native_print,
+    ]
+}
+
+/// Heap address 0 is the null/default sentinel (`Value::Ref(0)`); it is
+/// never swept or handed out by `alloc`.
+const NULL_ADDR: usize = 0;
+
+/// Once the heap grows to this many slots, `alloc` runs a collection
+/// before handing out any more.
+const GC_HIGH_WATER: usize = 1000;
+
+/// Hard cap on heap growth: if a collection at `GC_HIGH_WATER` doesn't free
+/// any slots and the heap is still this large, the actor is out of memory
+/// rather than growing forever.
+const HEAP_MAX: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+struct GcStats {
+    collections: u64,
+    bytes_reclaimed: u64,
+}
+
 struct ActorVm {
+    pid: usize,
     register: Register,
     clock_count: u64,
     cpu: u64,
     stack: Vec<Value>,
     heap: Vec<Value>,
+    free_list: Vec<usize>,
+    gc_stats: GcStats,
     mailbox: Mailbox,
     lock: std::sync::Mutex<()>,
     program: Vec<Inst>,
-    sender: fn(Value, Value),
+    natives: Vec<NativeFn>,
+    running: bool,
+    fault: Option<Fault>,
+    trap_pc: Option<usize>,
+    /// `heap.len()` as of the last GC check, so `alloc` only reconsiders
+    /// collecting once the heap has actually grown past that point instead
+    /// of re-scanning on every call once `GC_HIGH_WATER` is crossed (`gc`
+    /// never shrinks `heap.len()`, it only grows `free_list`).
+    gc_watermark: usize,
+}
+
+/// The on-wire form of an `ActorVm`, produced by `snapshot` and consumed
+/// by `restore`. Captures everything needed to resume execution exactly
+/// where it left off, including on a different scheduler instance
+/// (live migration) or after being persisted to disk.
+#[derive(Debug, Serialize, Deserialize)]
+struct ActorSnapshot {
+    pid: usize,
+    registers: Vec<Value>,
+    clock_count: u64,
+    cpu: u64,
+    stack: Vec<Value>,
+    heap: Vec<Value>,
+    free_list: Vec<usize>,
+    gc_stats: GcStats,
+    mailbox: Vec<Value>,
+    program: Vec<Inst>,
+    pc: usize,
     running: bool,
+    trap_pc: Option<usize>,
+}
+
+/// Marks `value` live, following `Ref`s (including those nested inside
+/// `List`/`Tuple`/`Map`) by pushing their heap addresses onto `worklist`
+/// for the caller to visit transitively.
+fn mark_value(value: &Value, worklist: &mut Vec<usize>) {
+    match value {
+        Value::Ref(r) if *r != NULL_ADDR => worklist.push(*r),
+        Value::List(items) | Value::Tuple(items) => {
+            for item in items {
+                mark_value(item, worklist);
+            }
+        }
+        Value::Map(map) => {
+            for (key, value) in map {
+                mark_value(key, worklist);
+                mark_value(value, worklist);
+            }
+        }
+        _ => {}
+    }
 }
 
 impl ActorVm {
@@ -214,14 +587,144 @@ impl ActorVm {
 
     fn release(&mut self) {}
 
-    fn pc(&self) -> usize {
-        let pc = self.register.get(Reg::PC);
-        match pc {
-            Value::Ref(r) => r,
-            _ => panic!("PC is not a reference"),
+    fn pc(&self) -> Result<usize, Fault> {
+        match self.register.get(Reg::PC) {
+            Value::Ref(r) => Ok(r),
+            other => Err(Fault::TypeMismatch {
+                inst: "pc",
+                got: format!("{:?}", other),
+            }),
+        }
+    }
+
+    fn fault(&self) -> Option<&Fault> {
+        self.fault.as_ref()
+    }
+
+    fn gc_stats(&self) -> GcStats {
+        self.gc_stats
+    }
+
+    /// Captures the full state needed to pause this actor and resume it
+    /// later, whether on this scheduler or another one.
+    fn snapshot(&self) -> ActorSnapshot {
+        ActorSnapshot {
+            pid: self.pid,
+            registers: clone_values(&self.register.registers),
+            clock_count: self.clock_count,
+            cpu: self.cpu,
+            stack: clone_values(&self.stack),
+            heap: clone_values(&self.heap),
+            free_list: self.free_list.clone(),
+            gc_stats: self.gc_stats,
+            mailbox: clone_values(&self.mailbox.messages),
+            program: self.program.clone(),
+            pc: self.pc().unwrap_or(NULL_ADDR),
+            running: self.running,
+            trap_pc: self.trap_pc,
         }
     }
 
+    /// Rebuilds an actor from a snapshot taken by `snapshot`, e.g. after
+    /// deserializing one that was persisted or migrated from elsewhere.
+    fn restore(snapshot: ActorSnapshot) -> ActorVm {
+        let mut registers = snapshot.registers.into_iter();
+        let registers: [Value; 12] =
+            std::array::from_fn(|_| registers.next().unwrap_or(Value::Ref(NULL_ADDR)));
+        ActorVm {
+            pid: snapshot.pid,
+            register: Register { registers },
+            clock_count: snapshot.clock_count,
+            cpu: snapshot.cpu,
+            stack: snapshot.stack,
+            heap: snapshot.heap,
+            free_list: snapshot.free_list,
+            gc_stats: snapshot.gc_stats,
+            mailbox: Mailbox {
+                messages: snapshot.mailbox,
+                lock: std::sync::Mutex::new(()),
+            },
+            lock: std::sync::Mutex::new(()),
+            program: snapshot.program,
+            natives: native_table(),
+            running: snapshot.running,
+            fault: None,
+            trap_pc: snapshot.trap_pc,
+            gc_watermark: 0,
+        }
+    }
+
+    /// Mark-and-sweep: marks every heap address reachable from the
+    /// registers, the stack and pending mailbox messages, then reclaims
+    /// every unmarked slot (other than `NULL_ADDR`) onto `free_list`.
+    fn gc(&mut self) {
+        let mut marked = vec![false; self.heap.len()];
+        let mut worklist: Vec<usize> = Vec::new();
+
+        for i in 0..self.register.registers.len() {
+            // PC, LR and Me stash a usize in a `Value::Ref` for convenience
+            // (a program counter, a return address, this actor's own pid)
+            // but none of them ever point into the heap, so marking them
+            // would keep whatever heap slot happens to share that number
+            // alive forever instead of sweeping it.
+            if i == Reg::PC as usize || i == Reg::LR as usize || i == Reg::Me as usize {
+                continue;
+            }
+            mark_value(&self.register.registers[i], &mut worklist);
+        }
+        for value in &self.stack {
+            mark_value(value, &mut worklist);
+        }
+        for value in &self.mailbox.messages {
+            mark_value(value, &mut worklist);
+        }
+
+        while let Some(addr) = worklist.pop() {
+            if addr >= marked.len() || marked[addr] {
+                continue;
+            }
+            marked[addr] = true;
+            mark_value(&self.heap[addr], &mut worklist);
+        }
+
+        let mut reclaimed = 0u64;
+        self.free_list.clear();
+        for (addr, is_marked) in marked.iter().enumerate() {
+            if addr == NULL_ADDR || *is_marked {
+                continue;
+            }
+            self.heap[addr] = Value::Ref(NULL_ADDR);
+            self.free_list.push(addr);
+            reclaimed += std::mem::size_of::<Value>() as u64;
+        }
+
+        self.gc_stats.collections += 1;
+        self.gc_stats.bytes_reclaimed += reclaimed;
+    }
+
+    /// Hands out a heap address, reusing a freed slot if one is available
+    /// and otherwise growing the heap. Runs a collection first once the
+    /// heap has crossed `GC_HIGH_WATER` *and* grown past `gc_watermark`
+    /// (the size as of the last check) — `gc` only ever grows `free_list`,
+    /// never shrinks `heap.len()`, so gating on length alone would re-scan
+    /// the whole heap on every call once it crossed the threshold once. If
+    /// a collection doesn't free anything and the heap has hit `HEAP_MAX`,
+    /// raises `HeapOverflow` instead of growing without bound.
+    fn alloc(&mut self) -> Result<usize, Fault> {
+        if self.heap.len() >= GC_HIGH_WATER && self.heap.len() > self.gc_watermark {
+            self.gc();
+            self.gc_watermark = self.heap.len();
+        }
+        if let Some(addr) = self.free_list.pop() {
+            return Ok(addr);
+        }
+        if self.heap.len() >= HEAP_MAX {
+            return Err(Fault::HeapOverflow);
+        }
+        self.heap.push(Value::Ref(NULL_ADDR));
+        Ok(self.heap.len() - 1)
+    }
+
     fn set_pc(&mut self, pc: usize) {
         self.register.set(Reg::PC, &Value::Ref(pc));
     }
@@ -234,16 +737,24 @@ impl ActorVm {
         self.register.set(reg, value);
     }
 
-    fn tick(&mut self) {
-        let pc = self.pc();
-        let inst: &Inst = &self.program[pc];
+    /// Executes the instruction at the current PC. When the instruction is a
+    /// `Send`, returns the destination pid and message so the scheduler can
+    /// deliver it to the right mailbox; every other instruction returns
+    /// `None`. Returns `Err(Fault)` instead of panicking when the
+    /// instruction cannot be carried out.
+    fn tick(&mut self) -> Result<Option<(usize, Value)>, Fault> {
+        let pc = self.pc()?;
+        let inst: &Inst = self.program.get(pc).ok_or(Fault::BadAddress)?;
         self.register.set(Reg::PC, &Value::Ref(pc + 1));
         match *inst {
             Inst::Load(address, reg) => {
-                let value = self.heap[address].clone();
+                let value = self.heap.get(address).ok_or(Fault::BadAddress)?.clone();
                 self.set_reg(reg, &value);
             }
             Inst::Store(reg, address) => {
+                if address >= self.heap.len() {
+                    return Err(Fault::BadAddress);
+                }
                 let value = self.get_reg(reg).clone();
                 self.heap[address] = value;
             }
@@ -290,12 +801,26 @@ impl ActorVm {
                 match t {
                     Value::List(mut list) => match k {
                         Value::Int(i) => {
-                            list[i as usize] = v.clone();
+                            let idx = i as usize;
+                            if idx >= list.len() {
+                                return Err(Fault::BadIndex);
+                            }
+                            list[idx] = v;
                             self.set_reg(target, &Value::List(list));
                         }
-                        _ => {}
+                        other => {
+                            return Err(Fault::TypeMismatch {
+                                inst: "setc",
+                                got: format!("{:?}", other),
+                            });
+                        }
                     },
-                    _ => {}
+                    other => {
+                        return Err(Fault::TypeMismatch {
+                            inst: "setc",
+                            got: format!("{:?}", other),
+                        });
+                    }
                 }
             }
             Inst::MoveC(from, key, to) => {
@@ -304,12 +829,26 @@ impl ActorVm {
                 match f {
                     Value::List(list) => match k {
                         Value::Int(i) => {
-                            let value = list[i as usize].clone();
+                            let idx = i as usize;
+                            if idx >= list.len() {
+                                return Err(Fault::BadIndex);
+                            }
+                            let value = list[idx].clone();
                             self.set_reg(to, &value);
                         }
-                        _ => {}
+                        other => {
+                            return Err(Fault::TypeMismatch {
+                                inst: "movec",
+                                got: format!("{:?}", other),
+                            });
+                        }
                     },
-                    _ => {}
+                    other => {
+                        return Err(Fault::TypeMismatch {
+                            inst: "movec",
+                            got: format!("{:?}", other),
+                        });
+                    }
                 }
             }
             Inst::Move(r1, r2) => {
@@ -326,7 +865,7 @@ impl ActorVm {
                     (Value::Float(v0), Value::Float(v1)) => {
                         self.set_reg(r2, &Value::Float(v0 + v1));
                     }
-                    _ => {}
+                    (v0, v1) => return Err(type_mismatch("add", &v0, &v1)),
                 }
             }
             Inst::Sub(r0, r1, r2) => {
@@ -339,7 +878,7 @@ impl ActorVm {
                     (Value::Float(v0), Value::Float(v1)) => {
                         self.set_reg(r2, &Value::Float(v0 - v1));
                     }
-                    _ => {}
+                    (v0, v1) => return Err(type_mismatch("sub", &v0, &v1)),
                 }
             }
             Inst::Mul(r0, r1, r2) => {
@@ -352,147 +891,161 @@ impl ActorVm {
                     (Value::Float(v0), Value::Float(v1)) => {
                         self.set_reg(r2, &Value::Float(v0 * v1));
                     }
-                    _ => {}
+                    (v0, v1) => return Err(type_mismatch("mul", &v0, &v1)),
                 }
             }
             Inst::Div(r0, r1, r2) => {
                 let v0 = self.get_reg(r0);
                 let v1 = self.get_reg(r1);
                 match (v0, v1) {
+                    (Value::Int(_), Value::Int(0)) => return Err(Fault::DivByZero),
                     (Value::Int(v0), Value::Int(v1)) => {
                         self.set_reg(r2, &Value::Int(v0 / v1));
                     }
                     (Value::Float(v0), Value::Float(v1)) => {
+                        if v1 == 0.0 {
+                            return Err(Fault::DivByZero);
+                        }
                         self.set_reg(r2, &Value::Float(v0 / v1));
                     }
-                    _ => {}
+                    (v0, v1) => return Err(type_mismatch("div", &v0, &v1)),
                 }
             }
             Inst::Mod(r0, r1, r2) => {
                 let v0 = self.get_reg(r0);
                 let v1 = self.get_reg(r1);
                 match (v0, v1) {
+                    (Value::Int(_), Value::Int(0)) => return Err(Fault::DivByZero),
                     (Value::Int(v0), Value::Int(v1)) => {
                         self.set_reg(r2, &Value::Int(v0 % v1));
                     }
                     (Value::Float(v0), Value::Float(v1)) => {
+                        if v1 == 0.0 {
+                            return Err(Fault::DivByZero);
+                        }
                         self.set_reg(r2, &Value::Float(v0 % v1));
                     }
-                    _ => {}
+                    (v0, v1) => return Err(type_mismatch("mod", &v0, &v1)),
                 }
             }
             Inst::Eq(r0, r1) => {
                 let v0 = self.get_reg(r0);
                 let v1 = self.get_reg(r1);
-                match (v0, v1) {
-                    (Value::Int(v0), Value::Int(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 == v1));
-                    }
-                    (Value::Float(v0), Value::Float(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 == v1));
-                    }
-                    (Value::String(v0), Value::String(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 == v1));
-                    }
-                    _ => {}
-                }
+                self.set_reg(Reg::ZF, &Value::Bool(v0 == v1));
             }
             Inst::Ne(r0, r1) => {
                 let v0 = self.get_reg(r0);
                 let v1 = self.get_reg(r1);
-                match (v0, v1) {
-                    (Value::Int(v0), Value::Int(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 != v1));
-                    }
-                    (Value::Float(v0), Value::Float(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 != v1));
-                    }
-                    (Value::String(v0), Value::String(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 != v1));
-                    }
-                    _ => panic!("Invalid comparison"),
-                }
+                self.set_reg(Reg::ZF, &Value::Bool(v0 != v1));
             }
             Inst::Gt(r0, r1) => {
                 let v0 = self.get_reg(r0);
                 let v1 = self.get_reg(r1);
-                match (v0, v1) {
-                    (Value::Int(v0), Value::Int(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 > v1));
-                    }
-                    (Value::Float(v0), Value::Float(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 > v1));
-                    }
-                    _ => panic!("Invalid comparison"),
-                }
+                self.set_reg(Reg::ZF, &Value::Bool(v0 > v1));
             }
             Inst::Gte(r0, r1) => {
                 let v0 = self.get_reg(r0);
                 let v1 = self.get_reg(r1);
-                match (v0, v1) {
-                    (Value::Int(v0), Value::Int(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 >= v1));
-                    }
-                    (Value::Float(v0), Value::Float(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 >= v1));
-                    }
-                    _ => panic!("Invalid comparison"),
-                }
+                self.set_reg(Reg::ZF, &Value::Bool(v0 >= v1));
             }
             Inst::Lt(r0, r1) => {
                 let v0 = self.get_reg(r0);
                 let v1 = self.get_reg(r1);
-                match (v0, v1) {
-                    (Value::Int(v0), Value::Int(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 < v1));
-                    }
-                    (Value::Float(v0), Value::Float(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 < v1));
-                    }
-                    _ => panic!("Invalid comparison"),
-                }
+                self.set_reg(Reg::ZF, &Value::Bool(v0 < v1));
             }
             Inst::Lte(r0, r1) => {
                 let v0 = self.get_reg(r0);
                 let v1 = self.get_reg(r1);
-                match (v0, v1) {
-                    (Value::Int(v0), Value::Int(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 <= v1));
-                    }
-                    (Value::Float(v0), Value::Float(v1)) => {
-                        self.set_reg(Reg::ZF, &Value::Bool(v0 <= v1));
-                    }
-                    _ => panic!("Invalid comparison"),
-                }
+                self.set_reg(Reg::ZF, &Value::Bool(v0 <= v1));
             }
             Inst::Jump(address) => {
                 self.set_pc(address);
             }
-            Inst::JumpIf(address) => {
-                let value = self.get_reg(Reg::ZF);
-                match value {
-                    Value::Bool(true) => {
-                        self.set_pc(address);
-                    }
-                    Value::Bool(false) => {}
-                    _ => panic!("Invalid comparison"),
+            Inst::JumpIf(address) => match self.get_reg(Reg::ZF) {
+                Value::Bool(true) => {
+                    self.set_pc(address);
                 }
-            }
+                Value::Bool(false) => {}
+                other => {
+                    return Err(Fault::TypeMismatch {
+                        inst: "jumpif",
+                        got: format!("{:?}", other),
+                    });
+                }
+            },
             Inst::Hlt => {
                 self.running = false;
             }
-            Inst::Send(reg, reg1) => todo!(),
-            Inst::Recv(reg) => todo!(),
+            Inst::Trap(address) => {
+                self.trap_pc = Some(address);
+            }
+            Inst::Alloc(reg) => {
+                let addr = self.alloc()?;
+                self.set_reg(reg, &Value::Ref(addr));
+            }
+            Inst::Call(func_id, argc) => {
+                if argc > self.stack.len() {
+                    return Err(Fault::StackUnderflow);
+                }
+                let split_at = self.stack.len() - argc;
+                let args = self.stack.split_off(split_at);
+                let native: NativeFn = *self.natives.get(func_id).ok_or(Fault::BadIndex)?;
+                let result = native(self, &args)?;
+                self.set_reg(Reg::R0, &result);
+            }
+            Inst::Send(addr_reg, msg_reg) => {
+                let addr = self.get_reg(addr_reg);
+                let msg = self.get_reg(msg_reg);
+                return match addr {
+                    Value::Ref(pid) => Ok(Some((pid, msg))),
+                    other => Err(Fault::TypeMismatch {
+                        inst: "send",
+                        got: format!("{:?}", other),
+                    }),
+                };
+            }
+            Inst::Recv(reg) => match self.mailbox.take() {
+                Some(value) => {
+                    self.set_reg(reg, &value);
+                }
+                None => {
+                    // Nothing to receive yet: stay on this instruction so the
+                    // scheduler re-evaluates it next quantum instead of
+                    // advancing past it.
+                    self.set_pc(pc);
+                }
+            },
             Inst::Push(reg) => {
                 let value = self.get_reg(reg).clone();
                 self.stack.push(value);
                 self.set_reg(reg, &Value::Ref(0));
             }
             Inst::Pop(reg) => {
-                let value = self.stack.pop().unwrap();
+                let value = self.stack.pop().ok_or(Fault::StackUnderflow)?;
                 self.set_reg(reg, &value);
             }
         }
+        Ok(None)
+    }
+
+    /// Runs one instruction, turning any `Fault` into actor-local state
+    /// instead of unwinding: if a fault handler has been installed via
+    /// `Trap`, execution resumes there, otherwise the actor halts.
+    fn step(&mut self) -> Option<(usize, Value)> {
+        match self.tick() {
+            Ok(sent) => {
+                self.fault = None;
+                sent
+            }
+            Err(fault) => {
+                self.fault = Some(fault);
+                match self.trap_pc {
+                    Some(handler) => self.set_pc(handler),
+                    None => self.running = false,
+                }
+                None
+            }
+        }
     }
 
     fn post(&mut self, value: Value) {
@@ -503,41 +1056,461 @@ impl ActorVm {
         self.register.show_reg();
     }
 
-    fn new(program: Vec<Inst>, sender: fn(Value, Value), cpu: u64) -> ActorVm {
+    fn new(pid: usize, program: Vec<Inst>, cpu: u64) -> ActorVm {
         ActorVm {
+            pid,
             clock_count: cpu,
-            cpu: cpu,
-            register: Register::new(),
+            cpu,
+            register: Register::new(pid),
             stack: Vec::new(),
-            heap: Vec::with_capacity(1000),
+            heap: vec![Value::Ref(NULL_ADDR)],
+            free_list: Vec::new(),
+            gc_stats: GcStats::default(),
             mailbox: Mailbox::new(),
             lock: std::sync::Mutex::new(()),
-            program: program,
-            sender: sender,
+            program,
+            natives: native_table(),
             running: true,
+            fault: None,
+            trap_pc: None,
+            gc_watermark: 0,
+        }
+    }
+}
+
+fn type_mismatch(inst: &'static str, v0: &Value, v1: &Value) -> Fault {
+    Fault::TypeMismatch {
+        inst,
+        got: format!("{:?}, {:?}", v0, v1),
+    }
+}
+
+/// Owns every actor in the system and cooperatively schedules their `tick()`
+/// calls, round-robin, spending up to each actor's `cpu` budget per quantum.
+/// `Value::Ref(pid)` doubles as an actor's address, so `Send` is resolved by
+/// looking the destination up in `actors`.
+struct Scheduler {
+    actors: HashMap<usize, ActorVm>,
+    next_pid: usize,
+}
+
+impl Scheduler {
+    fn new() -> Scheduler {
+        Scheduler {
+            actors: HashMap::new(),
+            next_pid: 1,
+        }
+    }
+
+    fn spawn(&mut self, program: Vec<Inst>, cpu: u64) -> usize {
+        let pid = self.next_pid;
+        self.next_pid += 1;
+        self.actors.insert(pid, ActorVm::new(pid, program, cpu));
+        pid
+    }
+
+    fn deliver(&mut self, pid: usize, value: Value) {
+        if let Some(actor) = self.actors.get_mut(&pid) {
+            actor.post(value);
         }
     }
+
+    /// Runs one scheduling quantum: every actor gets up to its own `cpu`
+    /// budget of ticks, in pid order, before the next actor runs.
+    fn run_quantum(&mut self) {
+        let mut pids: Vec<usize> = self.actors.keys().copied().collect();
+        pids.sort_unstable();
+        for pid in pids {
+            let budget = match self.actors.get(&pid) {
+                Some(actor) => actor.cpu,
+                None => continue,
+            };
+            for _ in 0..budget {
+                let sent = {
+                    let actor = match self.actors.get_mut(&pid) {
+                        Some(actor) => actor,
+                        None => break,
+                    };
+                    if !actor.running || !actor.get_tick() {
+                        break;
+                    }
+                    let sent = actor.step();
+                    actor.release();
+                    sent
+                };
+                if let Some((dest, value)) = sent {
+                    self.deliver(dest, value);
+                }
+            }
+        }
+    }
+
+    fn all_halted(&self) -> bool {
+        self.actors.values().all(|actor| !actor.running)
+    }
+
+    /// Snapshots an actor so it can be persisted or migrated elsewhere.
+    fn snapshot(&self, pid: usize) -> Option<ActorSnapshot> {
+        self.actors.get(&pid).map(|actor| actor.snapshot())
+    }
+
+    /// Restores a snapshot taken from this or another scheduler, keeping
+    /// its original pid so in-flight `Send`s still resolve to it.
+    fn migrate_in(&mut self, snapshot: ActorSnapshot) -> usize {
+        let pid = snapshot.pid;
+        self.actors.insert(pid, ActorVm::restore(snapshot));
+        self.next_pid = self.next_pid.max(pid + 1);
+        pid
+    }
 }
 
-fn sender(value: Value, to: Value) {
-    // Implement the sender function
-    println!("Sender function called, value: {:?}, to: {:?}", value, to);
+/// When invoked as `actor-vm <path>.asm` (only available with the `disasm`
+/// feature), assembles the named file and runs it as the sole actor instead
+/// of the hardcoded ping/pong/greeter demo below.
+#[cfg(feature = "disasm")]
+fn run_asm_file(path: &str) {
+    let source = std::fs::read_to_string(path).expect("failed to read asm file");
+    let program = disasm::assemble(&source).expect("failed to assemble program");
+
+    // Echo the assembled program back as disassembled text so the caller
+    // can confirm what actually got parsed before it runs.
+    println!("{}", disasm::disassemble(&program));
+
+    let mut scheduler = Scheduler::new();
+    scheduler.spawn(program, 10);
+    while !scheduler.all_halted() {
+        scheduler.run_quantum();
+    }
+    for actor in scheduler.actors.values() {
+        actor.show_reg();
+    }
 }
 
 fn main() {
-    let pro = vec![
-        Inst::Int(Reg::R0, 1),   // max
-        Inst::Int(Reg::R1, 123), // sum
-        Inst::List(Reg::R2, 10), // list
-        Inst::SetC(Reg::R2, Reg::R0, Reg::R1),
+    #[cfg(feature = "disasm")]
+    if let Some(path) = std::env::args().nth(1) {
+        run_asm_file(&path);
+        return;
+    }
+
+    let mut scheduler = Scheduler::new();
+
+    let pong = scheduler.spawn(vec![Inst::Recv(Reg::R0), Inst::Hlt], 10);
+
+    let ping = vec![
+        Inst::Ref(Reg::R0, pong),
+        Inst::Int(Reg::R1, 42),
+        Inst::Send(Reg::R0, Reg::R1),
+        Inst::Hlt,
+    ];
+    scheduler.spawn(ping, 10);
+
+    // Greet via the string stdlib: concatenate two strings and print the
+    // result through the native `print` call.
+    let greeter = vec![
+        Inst::String(Reg::R0, "hello, ".to_string()),
+        Inst::String(Reg::R1, "actor".to_string()),
+        Inst::Push(Reg::R0),
+        Inst::Push(Reg::R1),
+        Inst::Call(NATIVE_STR_CONCAT, 2),
+        Inst::Push(Reg::R0),
+        Inst::Call(NATIVE_PRINT, 1),
+        Inst::Hlt,
+    ];
+    let greeter_pid = scheduler.spawn(greeter, 10);
+
+    // Round out the stdlib demo: build a one-element list, grow and map it,
+    // stash a value in a map, and convert it back and forth to a float.
+    let stdlib_demo = vec![
+        Inst::String(Reg::R0, "hello".to_string()),
+        Inst::Push(Reg::R0),
+        Inst::Call(NATIVE_STR_LEN, 1),
+        Inst::Push(Reg::R0),
+        Inst::Call(NATIVE_PRINT, 1),
+        Inst::List(Reg::R0, 0),
+        Inst::Int(Reg::R1, 5),
+        Inst::Push(Reg::R0),
+        Inst::Push(Reg::R1),
+        Inst::Call(NATIVE_LIST_PUSH, 2),
+        Inst::Push(Reg::R0),
+        Inst::Call(NATIVE_LIST_LEN, 1),
+        Inst::Push(Reg::R0),
+        Inst::Call(NATIVE_PRINT, 1),
+        Inst::List(Reg::R0, 0),
+        Inst::Int(Reg::R1, 5),
+        Inst::Push(Reg::R0),
+        Inst::Push(Reg::R1),
+        Inst::Call(NATIVE_LIST_PUSH, 2),
+        Inst::Push(Reg::R0),
+        Inst::Int(Reg::R1, NATIVE_INT_TO_FLOAT as i64),
+        Inst::Push(Reg::R1),
+        Inst::Call(NATIVE_LIST_MAP, 2),
+        Inst::Push(Reg::R0),
+        Inst::Call(NATIVE_PRINT, 1),
+        Inst::Map(Reg::R0),
+        Inst::Atom(Reg::R1, "answer".to_string()),
+        Inst::Int(Reg::R2, 42),
+        Inst::Push(Reg::R0),
+        Inst::Push(Reg::R1),
+        Inst::Push(Reg::R2),
+        Inst::Call(NATIVE_MAP_INSERT, 3),
+        Inst::Push(Reg::R0),
+        Inst::Atom(Reg::R1, "answer".to_string()),
+        Inst::Push(Reg::R1),
+        Inst::Call(NATIVE_MAP_GET, 2),
+        Inst::Push(Reg::R0),
+        Inst::Call(NATIVE_INT_TO_FLOAT, 1),
+        Inst::Push(Reg::R0),
+        Inst::Call(NATIVE_FLOAT_TO_INT, 1),
+        Inst::Push(Reg::R0),
+        Inst::Call(NATIVE_PRINT, 1),
         Inst::Hlt,
     ];
-    let mut actor = ActorVm::new(pro, sender, 1000);
-    while actor.running {
+    scheduler.spawn(stdlib_demo, 10);
+
+    while !scheduler.all_halted() {
+        scheduler.run_quantum();
+    }
+
+    for actor in scheduler.actors.values() {
         actor.show_reg();
-        let mut buffer = String::new();
-        io::stdin().read_line(&mut buffer);
-        actor.tick();
     }
-    actor.show_reg();
+
+    // Demonstrate migration: snapshot the greeter actor and resume it on a
+    // brand new scheduler, showing its registers and GC stats carried over
+    // intact. A fault is not part of a snapshot, so the migrated actor's
+    // fault is always `None`.
+    if let Some(snapshot) = scheduler.snapshot(greeter_pid) {
+        let mut migrated = Scheduler::new();
+        let migrated_pid = migrated.migrate_in(snapshot);
+        if let Some(actor) = migrated.actors.get(&migrated_pid) {
+            println!(
+                "migrated actor {}: fault={:?}, gc_stats={:?}",
+                migrated_pid,
+                actor.fault(),
+                actor.gc_stats()
+            );
+            actor.show_reg();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gc_does_not_root_pc_me_or_lr() {
+        let mut vm = ActorVm::new(1, vec![], 10);
+        let live = vm.alloc().unwrap();
+        vm.set_reg(Reg::R0, &Value::Ref(live));
+        let dead = vm.alloc().unwrap();
+
+        // Nothing actually points at `dead`, but it happens to share a
+        // number with PC/LR/Me, which must not be mistaken for pointers.
+        vm.set_pc(dead);
+        vm.set_reg(Reg::LR, &Value::Ref(dead));
+        vm.set_reg(Reg::Me, &Value::Ref(dead));
+
+        vm.gc();
+
+        assert!(vm.free_list.contains(&dead));
+        assert!(!vm.free_list.contains(&live));
+        assert_eq!(vm.gc_stats().collections, 1);
+        assert!(vm.gc_stats().bytes_reclaimed > 0);
+    }
+
+    #[test]
+    fn alloc_raises_heap_overflow_when_full() {
+        let mut vm = ActorVm::new(1, vec![], 10);
+        let mut result = Ok(0);
+        for _ in 0..(HEAP_MAX + 10) {
+            result = vm.alloc();
+            match result {
+                Ok(addr) => vm.stack.push(Value::Ref(addr)),
+                Err(_) => break,
+            }
+        }
+        assert!(matches!(result, Err(Fault::HeapOverflow)));
+    }
+
+    #[test]
+    fn alloc_does_not_rescan_the_heap_once_free_slots_are_available() {
+        let mut vm = ActorVm::new(1, vec![], 10);
+
+        // Nothing keeps any of these live, so crossing GC_HIGH_WATER triggers
+        // exactly one collection that frees nearly the whole heap.
+        for _ in 0..(GC_HIGH_WATER + 5) {
+            vm.alloc().unwrap();
+        }
+        assert_eq!(vm.gc_stats().collections, 1);
+        assert!(!vm.free_list.is_empty());
+
+        // heap.len() hasn't grown since that collection (these reuse
+        // free_list), so none of these should trigger another one.
+        for _ in 0..10 {
+            vm.alloc().unwrap();
+        }
+        assert_eq!(vm.gc_stats().collections, 1);
+    }
+
+    #[test]
+    fn type_mismatch_reports_which_call_and_what_it_got() {
+        let mut vm = ActorVm::new(1, vec![], 10);
+        let err = native_str_concat(&mut vm, &[Value::Int(1), Value::Int(2)]).unwrap_err();
+
+        match err {
+            Fault::TypeMismatch { inst, got } => {
+                assert_eq!(inst, "call:str_concat");
+                assert_eq!(got, "[Int(1), Int(2)]");
+            }
+            other => panic!("expected TypeMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn fault_clears_after_a_clean_tick() {
+        let mut vm = ActorVm::new(1, vec![Inst::Int(Reg::R0, 1), Inst::Hlt], 10);
+        vm.fault = Some(Fault::DivByZero);
+
+        vm.step();
+
+        assert!(vm.fault().is_none());
+    }
+
+    #[test]
+    fn trap_handler_resumes_and_clears_fault() {
+        let program = vec![
+            Inst::Trap(3),
+            Inst::Pop(Reg::R0), // faults: StackUnderflow, diverts to handler
+            Inst::Hlt,
+            Inst::Int(Reg::R0, 99), // handler
+            Inst::Hlt,
+        ];
+        let mut vm = ActorVm::new(1, program, 10);
+        while vm.running {
+            vm.step();
+        }
+
+        assert!(vm.fault().is_none());
+        assert_eq!(vm.get_reg(Reg::R0), Value::Int(99));
+    }
+
+    #[test]
+    fn native_stdlib_via_call_instruction() {
+        // `Push` consumes (clears) its source register, so a list built once
+        // can only be pushed as an argument a single time; where a value is
+        // fed into two separate calls below it's rebuilt rather than reused.
+        let program = vec![
+            // str_len("hi") -> 2, stashed in R7
+            Inst::String(Reg::R0, "hi".to_string()),
+            Inst::Push(Reg::R0),
+            Inst::Call(NATIVE_STR_LEN, 1),
+            Inst::Move(Reg::R0, Reg::R7),
+            // list_push([], 5) -> [5]; list_len([5]) -> 1, stashed in R3
+            Inst::List(Reg::R0, 0),
+            Inst::Int(Reg::R2, 5),
+            Inst::Push(Reg::R0),
+            Inst::Push(Reg::R2),
+            Inst::Call(NATIVE_LIST_PUSH, 2),
+            Inst::Push(Reg::R0),
+            Inst::Call(NATIVE_LIST_LEN, 1),
+            Inst::Move(Reg::R0, Reg::R3),
+            // list_push([], 5) again; list_map([5], int_to_float) -> [5.0], stashed in R5
+            Inst::List(Reg::R0, 0),
+            Inst::Int(Reg::R2, 5),
+            Inst::Push(Reg::R0),
+            Inst::Push(Reg::R2),
+            Inst::Call(NATIVE_LIST_PUSH, 2),
+            Inst::Push(Reg::R0),
+            Inst::Int(Reg::R4, NATIVE_INT_TO_FLOAT as i64),
+            Inst::Push(Reg::R4),
+            Inst::Call(NATIVE_LIST_MAP, 2),
+            Inst::Move(Reg::R0, Reg::R5),
+            // map_insert({}, :k, 9) then map_get(..., :k) -> 9, stashed in R6
+            Inst::Map(Reg::R0),
+            Inst::Atom(Reg::R1, "k".to_string()),
+            Inst::Int(Reg::R2, 9),
+            Inst::Push(Reg::R0),
+            Inst::Push(Reg::R1),
+            Inst::Push(Reg::R2),
+            Inst::Call(NATIVE_MAP_INSERT, 3),
+            Inst::Push(Reg::R0),
+            Inst::Atom(Reg::R1, "k".to_string()),
+            Inst::Push(Reg::R1),
+            Inst::Call(NATIVE_MAP_GET, 2),
+            Inst::Move(Reg::R0, Reg::R6),
+            // int_to_float(3) then float_to_int(..) -> 3, left in R0
+            Inst::Int(Reg::R0, 3),
+            Inst::Push(Reg::R0),
+            Inst::Call(NATIVE_INT_TO_FLOAT, 1),
+            Inst::Push(Reg::R0),
+            Inst::Call(NATIVE_FLOAT_TO_INT, 1),
+            Inst::Hlt,
+        ];
+        let mut vm = ActorVm::new(1, program, 100);
+        while vm.running {
+            vm.step();
+        }
+
+        assert!(vm.fault().is_none(), "fault: {:?}", vm.fault());
+        assert_eq!(vm.get_reg(Reg::R7), Value::Int(2));
+        assert_eq!(vm.get_reg(Reg::R3), Value::Int(1));
+        assert_eq!(vm.get_reg(Reg::R5), Value::List(vec![Value::Float(5.0)]));
+        assert_eq!(vm.get_reg(Reg::R6), Value::Int(9));
+        assert_eq!(vm.get_reg(Reg::R0), Value::Int(3));
+    }
+
+    #[test]
+    fn scheduler_migrate_in_resumes_on_another_scheduler() {
+        let mut origin = Scheduler::new();
+        let pid = origin.spawn(vec![Inst::Int(Reg::R0, 7), Inst::Hlt], 10);
+        origin.run_quantum();
+
+        let snapshot = origin.snapshot(pid).expect("actor should exist");
+
+        let mut destination = Scheduler::new();
+        let migrated_pid = destination.migrate_in(snapshot);
+
+        assert_eq!(migrated_pid, pid);
+        let actor = destination.actors.get(&pid).expect("migrated actor");
+        assert_eq!(actor.get_reg(Reg::R0), Value::Int(7));
+    }
+
+    #[test]
+    fn snapshot_round_trips_through_serde() {
+        let program = vec![Inst::Int(Reg::R0, 7), Inst::Hlt];
+        let mut vm = ActorVm::new(3, program, 10);
+        vm.step();
+
+        let json = serde_json::to_string(&vm.snapshot()).unwrap();
+        let snapshot: ActorSnapshot = serde_json::from_str(&json).unwrap();
+        let restored = ActorVm::restore(snapshot);
+
+        assert_eq!(restored.pid, 3);
+        assert_eq!(restored.get_reg(Reg::R0), Value::Int(7));
+        assert_eq!(restored.pc().unwrap(), vm.pc().unwrap());
+    }
+
+    #[cfg(feature = "disasm")]
+    #[test]
+    fn assemble_disassemble_round_trips() {
+        let program = vec![
+            Inst::Int(Reg::R0, 7),
+            Inst::Jump(0),
+            Inst::Call(NATIVE_PRINT, 1),
+            Inst::Hlt,
+        ];
+        let text = disasm::disassemble(&program);
+        let reassembled = disasm::assemble(&text).unwrap();
+
+        assert_eq!(disasm::disassemble(&reassembled), text);
+    }
+
+    #[cfg(feature = "disasm")]
+    #[test]
+    fn assemble_rejects_regcount_as_a_register_name() {
+        let err = disasm::assemble("int regcount, 5\n").unwrap_err();
+        assert!(err.message.contains("regcount"), "{}", err.message);
+    }
 }